@@ -0,0 +1,19 @@
+use async_rust::abortable::abortable;
+use async_rust::thread_executor::LocalExecutor;
+use async_rust::thread_timer::ThreadTimer;
+use std::time::Duration;
+
+fn main() {
+    let (task, handle) = abortable(ThreadTimer::new(Duration::from_secs(5)));
+
+    let executor = LocalExecutor::new();
+    executor.spawn(async move {
+        ThreadTimer::new(Duration::from_millis(100)).await;
+        handle.abort();
+    });
+
+    match executor.run_until(task) {
+        Ok(()) => println!("Timer finished before it was aborted"),
+        Err(_aborted) => println!("Timer was aborted before it finished"),
+    }
+}
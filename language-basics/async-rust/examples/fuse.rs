@@ -0,0 +1,17 @@
+use async_rust::fuse::{Fuse, FusedFuture};
+use std::pin::pin;
+use std::task::{Context, Poll, Waker};
+
+fn main() {
+    let mut fused = pin!(Fuse::new(async { "done" }));
+    let mut context = Context::from_waker(Waker::noop());
+
+    // First poll runs the inner future to completion.
+    assert_eq!(fused.as_mut().poll(&mut context), Poll::Ready("done"));
+    assert!(fused.is_terminated());
+
+    // A plain async block would panic if polled again; `Fuse` just returns `Pending` instead.
+    assert_eq!(fused.as_mut().poll(&mut context), Poll::Pending);
+
+    println!("Polling a finished future again returned Pending instead of panicking");
+}
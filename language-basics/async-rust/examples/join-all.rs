@@ -0,0 +1,18 @@
+use async_rust::join_all::JoinAll;
+use async_rust::thread_executor::block_thread_on;
+use async_rust::thread_timer::ThreadTimer;
+use std::time::{Duration, Instant};
+
+fn main() {
+    let now = Instant::now();
+
+    let timers = vec![
+        ThreadTimer::new(Duration::from_secs(1)),
+        ThreadTimer::new(Duration::from_secs(2)),
+        ThreadTimer::new(Duration::from_secs(3)),
+    ];
+
+    block_thread_on(JoinAll::new(timers));
+
+    println!("All three timers finished in {}s", now.elapsed().as_secs());
+}
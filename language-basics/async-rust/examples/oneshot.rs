@@ -0,0 +1,19 @@
+use async_rust::oneshot::channel;
+use async_rust::thread_executor::LocalExecutor;
+use async_rust::thread_timer::ThreadTimer;
+use std::time::Duration;
+
+fn main() {
+    let (sender, receiver) = channel();
+
+    let executor = LocalExecutor::new();
+    executor.spawn(async move {
+        ThreadTimer::new(Duration::from_millis(100)).await;
+        sender.send("the answer is 42");
+    });
+
+    match executor.run_until(receiver) {
+        Ok(value) => println!("Received: {value}"),
+        Err(_canceled) => println!("Sender was dropped before sending"),
+    }
+}
@@ -0,0 +1,24 @@
+use async_rust::fake_worker::FakeWorker;
+use async_rust::select::{Either, Select};
+use std::pin::pin;
+use std::task::{Context, Poll, Waker};
+
+fn main() {
+    let fast = FakeWorker { work_remaining: 1 };
+    let slow = FakeWorker { work_remaining: 5 };
+
+    let mut race = pin!(Select::new(fast, slow));
+    let mut context = Context::from_waker(Waker::noop());
+
+    let winner = loop {
+        match race.as_mut().poll(&mut context) {
+            Poll::Ready(winner) => break winner,
+            Poll::Pending => continue,
+        }
+    };
+
+    match winner {
+        Either::Left(output) => println!("Fast worker finished first: {output}"),
+        Either::Right(output) => println!("Slow worker finished first: {output}"),
+    }
+}
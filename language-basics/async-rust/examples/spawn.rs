@@ -0,0 +1,19 @@
+use async_rust::thread_executor::LocalExecutor;
+use async_rust::thread_timer::ThreadTimer;
+use std::time::Duration;
+
+fn main() {
+    let executor = LocalExecutor::new();
+
+    for id in 1..=3 {
+        executor.spawn(async move {
+            ThreadTimer::new(Duration::from_millis(id * 100)).await;
+            println!("Background task {id} finished");
+        });
+    }
+
+    executor.run_until(async {
+        ThreadTimer::new(Duration::from_millis(500)).await;
+        println!("Main task finished");
+    });
+}
@@ -0,0 +1,14 @@
+use async_rust::stream::{StreamExt, iter};
+use async_rust::thread_executor::block_thread_on;
+
+fn main() {
+    let total = block_thread_on(
+        iter(1..=10)
+            .filter(|n| n % 2 == 0)
+            .map(|n| n * n)
+            .collect(),
+    );
+    println!("Squares of the evens from 1 to 10: {total:?}");
+
+    block_thread_on(iter(1..=3).for_each(|n| println!("Saw item {n}")));
+}
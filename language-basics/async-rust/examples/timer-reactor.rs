@@ -0,0 +1,16 @@
+use async_rust::join_all::JoinAll;
+use async_rust::thread_executor::block_thread_on;
+use async_rust::thread_timer::ThreadTimer;
+use std::time::Duration;
+
+fn main() {
+    // All ten timers below are served by the single `TimerReactor` background thread,
+    // not ten sleeping threads of their own.
+    let timers = (0..10)
+        .map(|_| ThreadTimer::new(Duration::from_millis(200)))
+        .collect();
+
+    block_thread_on(JoinAll::new(timers));
+
+    println!("All ten timers finished, served by one reactor thread");
+}
@@ -0,0 +1,58 @@
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
+#[derive(Debug)]
+pub struct Aborted;
+
+pub struct AbortHandle {
+    aborted: Arc<AtomicBool>,
+    waker: Arc<Mutex<Waker>>,
+}
+
+impl AbortHandle {
+    pub fn abort(&self) {
+        self.aborted.store(true, Ordering::SeqCst);
+        self.waker
+            .lock()
+            .expect("Thread crashed with mutex lock")
+            .wake_by_ref();
+    }
+}
+
+pub struct Abortable<F: Future> {
+    future: Pin<Box<F>>,
+    aborted: Arc<AtomicBool>,
+    waker: Arc<Mutex<Waker>>,
+}
+
+impl<F: Future> Future for Abortable<F> {
+    type Output = Result<F::Output, Aborted>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let inner = self.get_mut();
+
+        *inner.waker.lock().expect("Thread crashed with mutex lock") = cx.waker().clone();
+
+        if inner.aborted.load(Ordering::SeqCst) {
+            return Poll::Ready(Err(Aborted));
+        }
+
+        inner.future.as_mut().poll(cx).map(Ok)
+    }
+}
+
+pub fn abortable<F: Future>(future: F) -> (Abortable<F>, AbortHandle) {
+    let aborted = Arc::new(AtomicBool::new(false));
+    let waker = Arc::new(Mutex::new(Waker::noop().clone()));
+
+    let abortable = Abortable {
+        future: Box::pin(future),
+        aborted: aborted.clone(),
+        waker: waker.clone(),
+    };
+    let handle = AbortHandle { aborted, waker };
+
+    (abortable, handle)
+}
@@ -0,0 +1,43 @@
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// A [`Future`] that knows whether it has already resolved, so callers can avoid polling
+/// it again (which most of this crate's raw futures don't tolerate).
+pub trait FusedFuture: Future {
+    fn is_terminated(&self) -> bool;
+}
+
+/// Wraps any future so that polling it after it has already returned `Ready` just returns
+/// `Pending` forever, instead of whatever the inner future would do (often a panic).
+pub struct Fuse<F: Future>(Option<Pin<Box<F>>>);
+
+impl<F: Future> Fuse<F> {
+    pub fn new(future: F) -> Self {
+        Self(Some(Box::pin(future)))
+    }
+}
+
+impl<F: Future> Future for Fuse<F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+
+        match &mut this.0 {
+            Some(future) => match future.as_mut().poll(cx) {
+                Poll::Ready(output) => {
+                    this.0 = None;
+                    Poll::Ready(output)
+                }
+                Poll::Pending => Poll::Pending,
+            },
+            None => Poll::Pending,
+        }
+    }
+}
+
+impl<F: Future> FusedFuture for Fuse<F> {
+    fn is_terminated(&self) -> bool {
+        self.0.is_none()
+    }
+}
@@ -0,0 +1,54 @@
+use crate::fuse::{Fuse, FusedFuture};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+pub struct JoinAll<F: Future> {
+    futures: Vec<Fuse<F>>,
+    // Boxed so `JoinAll<F>` is `Unpin` regardless of whether `F::Output` is.
+    outputs: Vec<Option<Box<F::Output>>>,
+    remaining: usize,
+}
+
+impl<F: Future> JoinAll<F> {
+    pub fn new(futures: Vec<F>) -> Self {
+        let remaining = futures.len();
+        let futures = futures.into_iter().map(Fuse::new).collect();
+        let outputs = (0..remaining).map(|_| None).collect();
+
+        Self {
+            futures,
+            outputs,
+            remaining,
+        }
+    }
+}
+
+impl<F: Future> Future for JoinAll<F> {
+    type Output = Vec<F::Output>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let inner = self.get_mut();
+
+        for (future, output) in inner.futures.iter_mut().zip(inner.outputs.iter_mut()) {
+            if future.is_terminated() {
+                continue;
+            }
+
+            if let Poll::Ready(value) = Pin::new(future).poll(cx) {
+                *output = Some(Box::new(value));
+                inner.remaining -= 1;
+            }
+        }
+
+        if inner.remaining == 0 {
+            let outputs = inner
+                .outputs
+                .iter_mut()
+                .map(|output| *output.take().expect("every future has resolved"))
+                .collect();
+            Poll::Ready(outputs)
+        } else {
+            Poll::Pending
+        }
+    }
+}
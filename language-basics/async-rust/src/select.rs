@@ -0,0 +1,39 @@
+use crate::fuse::{Fuse, FusedFuture};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// The output of a [`Select`], telling you which of the two futures resolved first.
+pub enum Either<L, R> {
+    Left(L),
+    Right(R),
+}
+
+pub struct Select<F1: Future, F2: Future>(Fuse<F1>, Fuse<F2>);
+
+impl<F1: Future, F2: Future> Select<F1, F2> {
+    pub fn new(future1: F1, future2: F2) -> Self {
+        Self(Fuse::new(future1), Fuse::new(future2))
+    }
+}
+
+impl<F1: Future, F2: Future> Future for Select<F1, F2> {
+    type Output = Either<F1::Output, F2::Output>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let inner = self.get_mut();
+
+        if !inner.0.is_terminated() {
+            if let Poll::Ready(output) = Pin::new(&mut inner.0).poll(cx) {
+                return Poll::Ready(Either::Left(output));
+            }
+        }
+
+        if !inner.1.is_terminated() {
+            if let Poll::Ready(output) = Pin::new(&mut inner.1).poll(cx) {
+                return Poll::Ready(Either::Right(output));
+            }
+        }
+
+        Poll::Pending
+    }
+}
@@ -0,0 +1,162 @@
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// Like [`Future`], but yields a sequence of values over time instead of just one,
+/// with `None` marking the end of the sequence.
+pub trait Stream {
+    type Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>>;
+}
+
+pub struct IterStream<I>(I);
+
+impl<I: Iterator + Unpin> Stream for IterStream<I> {
+    type Item = I::Item;
+
+    fn poll_next(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Poll::Ready(self.get_mut().0.next())
+    }
+}
+
+/// Lifts a plain [`Iterator`] into a [`Stream`] whose items are always immediately ready.
+pub fn iter<I: IntoIterator>(iter: I) -> IterStream<I::IntoIter> {
+    IterStream(iter.into_iter())
+}
+
+pub struct Next<'a, S: ?Sized>(&'a mut S);
+
+impl<S: Stream + Unpin + ?Sized> Future for Next<'_, S> {
+    type Output = Option<S::Item>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut *self.get_mut().0).poll_next(cx)
+    }
+}
+
+pub struct Map<S, F> {
+    stream: S,
+    f: F,
+}
+
+impl<S: Stream + Unpin, B, F: FnMut(S::Item) -> B + Unpin> Stream for Map<S, F> {
+    type Item = B;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        Pin::new(&mut this.stream)
+            .poll_next(cx)
+            .map(|item| item.map(&mut this.f))
+    }
+}
+
+pub struct Filter<S, F> {
+    stream: S,
+    predicate: F,
+}
+
+impl<S: Stream + Unpin, F: FnMut(&S::Item) -> bool + Unpin> Stream for Filter<S, F> {
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        loop {
+            match Pin::new(&mut this.stream).poll_next(cx) {
+                Poll::Ready(Some(item)) if (this.predicate)(&item) => {
+                    return Poll::Ready(Some(item));
+                }
+                Poll::Ready(Some(_)) => continue,
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+pub struct Collect<S: Stream> {
+    stream: S,
+    items: Vec<S::Item>,
+}
+
+impl<S: Stream + Unpin> Future for Collect<S>
+where
+    S::Item: Unpin,
+{
+    type Output = Vec<S::Item>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        loop {
+            match Pin::new(&mut this.stream).poll_next(cx) {
+                Poll::Ready(Some(item)) => this.items.push(item),
+                Poll::Ready(None) => return Poll::Ready(std::mem::take(&mut this.items)),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+pub struct ForEach<S, F> {
+    stream: S,
+    f: F,
+}
+
+impl<S: Stream + Unpin, F: FnMut(S::Item) + Unpin> Future for ForEach<S, F> {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        loop {
+            match Pin::new(&mut this.stream).poll_next(cx) {
+                Poll::Ready(Some(item)) => (this.f)(item),
+                Poll::Ready(None) => return Poll::Ready(()),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+pub trait StreamExt: Stream {
+    fn next(&mut self) -> Next<'_, Self>
+    where
+        Self: Unpin,
+    {
+        Next(self)
+    }
+
+    fn map<B, F: FnMut(Self::Item) -> B>(self, f: F) -> Map<Self, F>
+    where
+        Self: Sized,
+    {
+        Map { stream: self, f }
+    }
+
+    fn filter<F: FnMut(&Self::Item) -> bool>(self, predicate: F) -> Filter<Self, F>
+    where
+        Self: Sized,
+    {
+        Filter {
+            stream: self,
+            predicate,
+        }
+    }
+
+    fn collect(self) -> Collect<Self>
+    where
+        Self: Sized,
+    {
+        Collect {
+            stream: self,
+            items: Vec::new(),
+        }
+    }
+
+    fn for_each<F: FnMut(Self::Item)>(self, f: F) -> ForEach<Self, F>
+    where
+        Self: Sized,
+    {
+        ForEach { stream: self, f }
+    }
+}
+
+impl<S: Stream + ?Sized> StreamExt for S {}
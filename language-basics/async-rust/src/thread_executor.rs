@@ -1,14 +1,15 @@
 use crate::thread_waker::ThreadWaker;
-use std::pin::pin;
-use std::sync::Arc;
-use std::task::{Context, Poll};
+use std::pin::{pin, Pin};
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Wake, Waker};
 
 pub fn block_thread_on<F: Future>(future: F) -> F::Output {
     let mut example = pin!(future);
 
     let waker = Arc::new(ThreadWaker::current_thread()).into();
     let mut context = Context::from_waker(&waker);
-    
+
     loop {
         match example.as_mut().poll(&mut context) {
             Poll::Pending => std::thread::park(),
@@ -16,3 +17,92 @@ pub fn block_thread_on<F: Future>(future: F) -> F::Output {
         }
     }
 }
+
+type BoxFuture = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+/// A unit of work on a [`LocalExecutor`]'s run queue.
+///
+/// Waking a `Task` just re-queues it; the executor polls it again on its next loop.
+struct Task {
+    future: Mutex<Option<BoxFuture>>,
+    ready_queue: Sender<Arc<Task>>,
+}
+
+impl Wake for Task {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.ready_queue
+            .send(self.clone())
+            .expect("Executor has shut down");
+    }
+}
+
+/// A minimal task-queue executor that can drive many spawned futures to completion.
+pub struct LocalExecutor {
+    ready_queue: Sender<Arc<Task>>,
+    ready_tasks: Receiver<Arc<Task>>,
+}
+
+impl LocalExecutor {
+    pub fn new() -> Self {
+        let (ready_queue, ready_tasks) = channel();
+        Self {
+            ready_queue,
+            ready_tasks,
+        }
+    }
+
+    pub fn spawn<F: Future<Output = ()> + Send + 'static>(&self, future: F) {
+        let task = Arc::new(Task {
+            future: Mutex::new(Some(Box::pin(future))),
+            ready_queue: self.ready_queue.clone(),
+        });
+        self.ready_queue
+            .send(task)
+            .expect("Executor has shut down");
+    }
+
+    /// Runs the spawned tasks alongside `main` until `main` completes, returning its output.
+    /// Spawned tasks that are still pending at that point are not polled any further.
+    pub fn run_until<F: Future + Send + 'static>(&self, main: F) -> F::Output
+    where
+        F::Output: Send + 'static,
+    {
+        let output = Arc::new(Mutex::new(None));
+        let output_for_main = output.clone();
+
+        self.spawn(async move {
+            *output_for_main.lock().expect("Thread crashed with mutex lock") = Some(main.await);
+        });
+
+        loop {
+            if let Some(result) = output.lock().expect("Thread crashed with mutex lock").take() {
+                break result;
+            }
+
+            let task = self
+                .ready_tasks
+                .recv()
+                .expect("Ready queue closed with tasks outstanding");
+
+            let mut slot = task.future.lock().expect("Thread crashed with mutex lock");
+            if let Some(mut future) = slot.take() {
+                let waker: Waker = task.clone().into();
+                let mut cx = Context::from_waker(&waker);
+
+                if future.as_mut().poll(&mut cx) == Poll::Pending {
+                    *slot = Some(future);
+                }
+            }
+        }
+    }
+}
+
+impl Default for LocalExecutor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
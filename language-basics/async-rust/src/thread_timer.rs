@@ -1,12 +1,12 @@
+use crate::timer_reactor::TimerReactor;
 use std::pin::Pin;
 use std::sync::{Arc, Mutex};
 use std::task::{Context, Poll, Waker};
-use std::thread::{JoinHandle, sleep, spawn};
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 pub struct ThreadTimer {
     duration: Duration,
-    join_handle: Option<JoinHandle<()>>,
+    deadline: Option<Instant>,
     waker: Arc<Mutex<Waker>>,
     is_complete: Arc<Mutex<bool>>,
 }
@@ -15,7 +15,7 @@ impl ThreadTimer {
     pub fn new(duration: Duration) -> ThreadTimer {
         Self {
             duration,
-            join_handle: None,
+            deadline: None,
             waker: Arc::new(Mutex::new(Waker::noop().clone())),
             is_complete: Arc::new(Mutex::new(false)),
         }
@@ -31,21 +31,11 @@ impl Future for ThreadTimer {
         // We always need to update the waker whenever we're polled
         *fut.waker.lock().expect("Thread crashed with mutex lock") = cx.waker().clone();
 
-        // If we haven't started the thread, do so now
-        if fut.join_handle.is_none() {
-            let duration = fut.duration;
-            let waker = fut.waker.clone();
-            let timer_complete = fut.is_complete.clone();
-            fut.join_handle = Some(spawn(move || {
-                sleep(duration);
-                *timer_complete
-                    .lock()
-                    .expect("Thread crashed with mutex lock") = true;
-                waker
-                    .lock()
-                    .expect("Thread crashed with mutex lock")
-                    .wake_by_ref();
-            }));
+        // If we haven't registered with the reactor yet, do so now
+        if fut.deadline.is_none() {
+            let deadline = Instant::now() + fut.duration;
+            fut.deadline = Some(deadline);
+            TimerReactor::register(deadline, fut.waker.clone(), fut.is_complete.clone());
         }
 
         match *fut
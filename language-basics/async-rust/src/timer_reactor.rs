@@ -0,0 +1,111 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::sync::{Arc, Condvar, Mutex, OnceLock};
+use std::task::Waker;
+use std::thread;
+use std::time::Instant;
+
+/// A single timer registered with the [`TimerReactor`].
+struct Registration {
+    deadline: Instant,
+    waker: Arc<Mutex<Waker>>,
+    is_complete: Arc<Mutex<bool>>,
+}
+
+// `BinaryHeap` is a max-heap, so we reverse the ordering to make it a min-heap by deadline.
+impl Ord for Registration {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.deadline.cmp(&self.deadline)
+    }
+}
+
+impl PartialOrd for Registration {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Registration {
+    fn eq(&self, other: &Self) -> bool {
+        self.deadline == other.deadline
+    }
+}
+
+impl Eq for Registration {}
+
+/// A lazily-started background thread that wakes every registered timer at its deadline,
+/// so a crate full of `ThreadTimer`s no longer needs a sleeping thread each.
+pub struct TimerReactor {
+    heap: Mutex<BinaryHeap<Registration>>,
+    condvar: Condvar,
+}
+
+impl TimerReactor {
+    fn global() -> &'static TimerReactor {
+        static INSTANCE: OnceLock<TimerReactor> = OnceLock::new();
+        INSTANCE.get_or_init(|| {
+            thread::spawn(TimerReactor::run);
+            TimerReactor {
+                heap: Mutex::new(BinaryHeap::new()),
+                condvar: Condvar::new(),
+            }
+        })
+    }
+
+    /// Registers a deadline with the reactor; `waker` is woken and `is_complete` set once it passes.
+    pub fn register(deadline: Instant, waker: Arc<Mutex<Waker>>, is_complete: Arc<Mutex<bool>>) {
+        let reactor = Self::global();
+        let mut heap = reactor.heap.lock().expect("Thread crashed with mutex lock");
+
+        let is_new_soonest = heap.peek().is_none_or(|next| deadline < next.deadline);
+        heap.push(Registration {
+            deadline,
+            waker,
+            is_complete,
+        });
+
+        if is_new_soonest {
+            reactor.condvar.notify_one();
+        }
+    }
+
+    fn run() {
+        let reactor = Self::global();
+
+        loop {
+            let heap = reactor.heap.lock().expect("Thread crashed with mutex lock");
+            let mut heap = match heap.peek() {
+                None => reactor
+                    .condvar
+                    .wait(heap)
+                    .expect("Thread crashed with mutex lock"),
+                Some(next) => {
+                    let now = Instant::now();
+                    if next.deadline > now {
+                        let wait_for = next.deadline - now;
+                        reactor
+                            .condvar
+                            .wait_timeout(heap, wait_for)
+                            .expect("Thread crashed with mutex lock")
+                            .0
+                    } else {
+                        heap
+                    }
+                }
+            };
+
+            while heap.peek().is_some_and(|next| next.deadline <= Instant::now()) {
+                let registration = heap.pop().expect("just checked with peek");
+                *registration
+                    .is_complete
+                    .lock()
+                    .expect("Thread crashed with mutex lock") = true;
+                registration
+                    .waker
+                    .lock()
+                    .expect("Thread crashed with mutex lock")
+                    .wake_by_ref();
+            }
+        }
+    }
+}
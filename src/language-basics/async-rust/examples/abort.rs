@@ -0,0 +1,18 @@
+use async_rust::abort::abortable;
+use async_rust::thread_executor::block_thread_on;
+use async_rust::thread_timer::ThreadTimer;
+use std::time::Duration;
+
+fn main() {
+    let (task, handle) = abortable(ThreadTimer::new(Duration::from_secs(5)));
+
+    std::thread::spawn(move || {
+        std::thread::sleep(Duration::from_millis(100));
+        handle.abort();
+    });
+
+    match block_thread_on(task) {
+        Ok(()) => println!("Timer finished before it was aborted"),
+        Err(_aborted) => println!("Timer was aborted before it finished"),
+    }
+}
@@ -0,0 +1,17 @@
+use async_rust::oneshot::channel;
+use async_rust::thread_executor::block_thread_on;
+use std::time::Duration;
+
+fn main() {
+    let (sender, receiver) = channel();
+
+    std::thread::spawn(move || {
+        std::thread::sleep(Duration::from_millis(100));
+        sender.send("the answer is 42");
+    });
+
+    match block_thread_on(receiver) {
+        Ok(value) => println!("Received: {value}"),
+        Err(_canceled) => println!("Sender was dropped before sending"),
+    }
+}
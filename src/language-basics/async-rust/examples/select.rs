@@ -0,0 +1,14 @@
+use async_rust::select::{Either, Select};
+use async_rust::thread_executor::block_thread_on;
+use async_rust::thread_timer::ThreadTimer;
+use std::time::Duration;
+
+fn main() {
+    let fast = ThreadTimer::new(Duration::from_millis(100));
+    let slow = ThreadTimer::new(Duration::from_secs(2));
+
+    match block_thread_on(Select::new(fast, slow)) {
+        Either::Left((), _slow) => println!("The fast timer finished first"),
+        Either::Right((), _fast) => println!("The slow timer finished first"),
+    }
+}
@@ -0,0 +1,42 @@
+use async_rust::stream::{IntervalStream, Stream, collect_stream_on};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+/// Takes the first `limit` ticks from `interval`, then ends the stream.
+struct Take {
+    interval: IntervalStream,
+    limit: usize,
+    seen: usize,
+}
+
+impl Stream for Take {
+    type Item = ();
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        if this.seen == this.limit {
+            return Poll::Ready(None);
+        }
+
+        match Pin::new(&mut this.interval).poll_next(cx) {
+            Poll::Ready(Some(())) => {
+                this.seen += 1;
+                Poll::Ready(Some(()))
+            }
+            other => other,
+        }
+    }
+}
+
+fn main() {
+    let take = Take {
+        interval: IntervalStream::new(Duration::from_millis(100)),
+        limit: 3,
+        seen: 0,
+    };
+
+    let ticks = collect_stream_on(take);
+    println!("Collected {} ticks", ticks.len());
+}
@@ -0,0 +1,29 @@
+use async_rust::stream::collect_stream_on;
+use async_rust::thread_timer::ThreadTimer;
+use async_rust::unordered::FuturesUnordered;
+use std::time::{Duration, Instant};
+
+fn main() {
+    let now = Instant::now();
+
+    let timers: FuturesUnordered<_> = [
+        Duration::from_millis(300),
+        Duration::from_millis(100),
+        Duration::from_millis(200),
+    ]
+    .map(|duration| {
+        let now = Instant::now();
+        async move {
+            ThreadTimer::new(duration).await;
+            now.elapsed().as_millis()
+        }
+    })
+    .into_iter()
+    .collect();
+
+    for elapsed in collect_stream_on(timers) {
+        println!("A timer finished after {elapsed}ms");
+    }
+
+    println!("All timers finished in {}ms", now.elapsed().as_millis());
+}
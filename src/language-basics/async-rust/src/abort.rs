@@ -0,0 +1,64 @@
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
+#[derive(Debug)]
+pub struct Aborted;
+
+pub struct AbortHandle {
+    aborted: Arc<AtomicBool>,
+    waker: Arc<Mutex<Option<Waker>>>,
+}
+
+impl AbortHandle {
+    pub fn abort(&self) {
+        self.aborted.store(true, Ordering::SeqCst);
+
+        if let Some(waker) = self
+            .waker
+            .lock()
+            .expect("Thread crashed with mutex lock")
+            .take()
+        {
+            waker.wake();
+        }
+    }
+}
+
+pub struct Abortable<F: Future> {
+    future: Pin<Box<F>>,
+    aborted: Arc<AtomicBool>,
+    waker: Arc<Mutex<Option<Waker>>>,
+}
+
+impl<F: Future> Future for Abortable<F> {
+    type Output = Result<F::Output, Aborted>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+
+        *this.waker.lock().expect("Thread crashed with mutex lock") = Some(cx.waker().clone());
+
+        if this.aborted.load(Ordering::SeqCst) {
+            return Poll::Ready(Err(Aborted));
+        }
+
+        this.future.as_mut().poll(cx).map(Ok)
+    }
+}
+
+/// Wraps `f` so it can be cancelled from the outside via the returned [`AbortHandle`].
+pub fn abortable<F: Future>(f: F) -> (Abortable<F>, AbortHandle) {
+    let aborted = Arc::new(AtomicBool::new(false));
+    let waker = Arc::new(Mutex::new(None));
+
+    let abortable = Abortable {
+        future: Box::pin(f),
+        aborted: aborted.clone(),
+        waker: waker.clone(),
+    };
+    let handle = AbortHandle { aborted, waker };
+
+    (abortable, handle)
+}
@@ -41,3 +41,53 @@ impl<F1: Future, F2: Future> Future for Join<F1, F2> {
         }
     }
 }
+
+pub struct JoinAll<F: Future> {
+    futures: Vec<Option<Pin<Box<F>>>>,
+    // Boxed so `JoinAll<F>` is `Unpin` regardless of whether `F::Output` is.
+    outputs: Vec<Option<Box<F::Output>>>,
+    remaining: usize,
+}
+
+impl<F: Future> Future for JoinAll<F> {
+    type Output = Vec<F::Output>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let inner = self.get_mut();
+
+        for (future, output) in inner.futures.iter_mut().zip(inner.outputs.iter_mut()) {
+            if let Some(pinned) = future {
+                if let Poll::Ready(value) = pinned.as_mut().poll(cx) {
+                    *future = None;
+                    *output = Some(Box::new(value));
+                    inner.remaining -= 1;
+                }
+            }
+        }
+
+        if inner.remaining == 0 {
+            let outputs = inner
+                .outputs
+                .iter_mut()
+                .map(|output| *output.take().expect("every future has resolved"))
+                .collect();
+            Poll::Ready(outputs)
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+/// Waits on an arbitrary number of homogeneous futures, resolving to their outputs in
+/// the same order the futures were given in, regardless of completion order.
+pub fn join_all<F: Future>(futures: Vec<F>) -> JoinAll<F> {
+    let remaining = futures.len();
+    let futures = futures.into_iter().map(|f| Some(Box::pin(f))).collect();
+    let outputs = (0..remaining).map(|_| None).collect();
+
+    JoinAll {
+        futures,
+        outputs,
+        remaining,
+    }
+}
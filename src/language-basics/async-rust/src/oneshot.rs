@@ -0,0 +1,66 @@
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
+#[derive(Debug)]
+pub struct Canceled;
+
+struct Shared<T> {
+    value: Option<T>,
+    waker: Option<Waker>,
+    sender_dropped: bool,
+}
+
+pub struct Sender<T>(Arc<Mutex<Shared<T>>>);
+
+impl<T> Sender<T> {
+    pub fn send(self, value: T) {
+        let mut shared = self.0.lock().expect("Thread crashed with mutex lock");
+        shared.value = Some(value);
+        if let Some(waker) = shared.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        let mut shared = self.0.lock().expect("Thread crashed with mutex lock");
+        shared.sender_dropped = true;
+        if let Some(waker) = shared.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+pub struct Receiver<T>(Arc<Mutex<Shared<T>>>);
+
+impl<T> Future for Receiver<T> {
+    type Output = Result<T, Canceled>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut shared = self.get_mut().0.lock().expect("Thread crashed with mutex lock");
+
+        if let Some(value) = shared.value.take() {
+            return Poll::Ready(Ok(value));
+        }
+
+        if shared.sender_dropped {
+            return Poll::Ready(Err(Canceled));
+        }
+
+        shared.waker = Some(cx.waker().clone());
+        Poll::Pending
+    }
+}
+
+/// Creates a one-shot channel for handing a single value from one task to another.
+pub fn channel<T>() -> (Sender<T>, Receiver<T>) {
+    let shared = Arc::new(Mutex::new(Shared {
+        value: None,
+        waker: None,
+        sender_dropped: false,
+    }));
+
+    (Sender(shared.clone()), Receiver(shared))
+}
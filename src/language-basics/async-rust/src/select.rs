@@ -0,0 +1,52 @@
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// The output of [`Select`], holding the winner's output plus the still-pending loser
+/// so the caller can keep polling it if they want.
+pub enum Either<A: Future, B: Future> {
+    Left(A::Output, Pin<Box<B>>),
+    Right(B::Output, Pin<Box<A>>),
+}
+
+pub struct Select<A: Future, B: Future> {
+    a: Option<Pin<Box<A>>>,
+    b: Option<Pin<Box<B>>>,
+}
+
+impl<A: Future, B: Future> Select<A, B> {
+    pub fn new(a: A, b: B) -> Self {
+        Self {
+            a: Some(Box::pin(a)),
+            b: Some(Box::pin(b)),
+        }
+    }
+}
+
+impl<A: Future, B: Future> Future for Select<A, B> {
+    type Output = Either<A, B>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+
+        let mut a = this.a.take().expect("Select polled after completion");
+        match a.as_mut().poll(cx) {
+            Poll::Ready(output) => {
+                let b = this.b.take().expect("Select polled after completion");
+                return Poll::Ready(Either::Left(output, b));
+            }
+            Poll::Pending => this.a = Some(a),
+        }
+
+        let mut b = this.b.take().expect("Select polled after completion");
+        match b.as_mut().poll(cx) {
+            Poll::Ready(output) => {
+                let a = this.a.take().expect("Select polled after completion");
+                Poll::Ready(Either::Right(output, a))
+            }
+            Poll::Pending => {
+                this.b = Some(b);
+                Poll::Pending
+            }
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use crate::thread_timer::ThreadTimer;
+use crate::thread_waker::ThreadWaker;
+use std::pin::{pin, Pin};
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+/// Like [`Future`], but yields a sequence of values over time instead of just one.
+/// A `None` from `poll_next` means the stream is finished.
+pub trait Stream {
+    type Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>>;
+}
+
+/// Drives `s` to completion on the current thread, parking between polls just like
+/// [`crate::thread_executor::block_thread_on`] does for a single future.
+pub fn collect_stream_on<S: Stream>(s: S) -> Vec<S::Item> {
+    let mut stream = pin!(s);
+
+    let waker = Arc::new(ThreadWaker::current_thread()).into();
+    let mut context = Context::from_waker(&waker);
+
+    let mut items = Vec::new();
+
+    loop {
+        match stream.as_mut().poll_next(&mut context) {
+            Poll::Ready(Some(item)) => items.push(item),
+            Poll::Ready(None) => break items,
+            Poll::Pending => std::thread::park(),
+        }
+    }
+}
+
+/// A stream that yields `()` once every `duration`, built on top of [`ThreadTimer`].
+pub struct IntervalStream {
+    duration: Duration,
+    timer: ThreadTimer,
+}
+
+impl IntervalStream {
+    pub fn new(duration: Duration) -> Self {
+        Self {
+            duration,
+            timer: ThreadTimer::new(duration),
+        }
+    }
+}
+
+impl Stream for IntervalStream {
+    type Item = ();
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        match Pin::new(&mut this.timer).poll(cx) {
+            Poll::Ready(()) => {
+                this.timer = ThreadTimer::new(this.duration);
+                Poll::Ready(Some(()))
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
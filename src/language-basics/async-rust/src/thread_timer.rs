@@ -0,0 +1,54 @@
+use crate::timer_reactor::TimerReactor;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+use std::time::{Duration, Instant};
+
+pub struct ThreadTimer {
+    duration: Duration,
+    registered: bool,
+    waker: Arc<Mutex<Option<Waker>>>,
+    is_complete: Arc<Mutex<bool>>,
+}
+
+impl ThreadTimer {
+    pub fn new(duration: Duration) -> ThreadTimer {
+        Self {
+            duration,
+            registered: false,
+            waker: Arc::new(Mutex::new(None)),
+            is_complete: Arc::new(Mutex::new(false)),
+        }
+    }
+}
+
+impl Future for ThreadTimer {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let fut = self.get_mut();
+
+        if *fut
+            .is_complete
+            .lock()
+            .expect("Thread crashed with mutex lock")
+        {
+            return Poll::Ready(());
+        }
+
+        // We always need to update the waker whenever we're polled
+        *fut.waker.lock().expect("Thread crashed with mutex lock") = Some(cx.waker().clone());
+
+        // If we haven't registered with the reactor yet, do so now
+        if !fut.registered {
+            fut.registered = true;
+            TimerReactor::register(
+                Instant::now() + fut.duration,
+                fut.waker.clone(),
+                fut.is_complete.clone(),
+            );
+        }
+
+        Poll::Pending
+    }
+}
@@ -0,0 +1,56 @@
+use crate::stream::Stream;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// A set of futures that yields each one's output as soon as it completes, instead of
+/// waiting for the slowest one like [`crate::join::join_all`] does.
+pub struct FuturesUnordered<F: Future> {
+    futures: Vec<Pin<Box<F>>>,
+}
+
+impl<F: Future> FuturesUnordered<F> {
+    pub fn new() -> Self {
+        Self {
+            futures: Vec::new(),
+        }
+    }
+
+    pub fn push(&mut self, future: F) {
+        self.futures.push(Box::pin(future));
+    }
+}
+
+impl<F: Future> Default for FuturesUnordered<F> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<F: Future> FromIterator<F> for FuturesUnordered<F> {
+    fn from_iter<I: IntoIterator<Item = F>>(iter: I) -> Self {
+        Self {
+            futures: iter.into_iter().map(|future| Box::pin(future)).collect(),
+        }
+    }
+}
+
+impl<F: Future> Stream for FuturesUnordered<F> {
+    type Item = F::Output;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        if this.futures.is_empty() {
+            return Poll::Ready(None);
+        }
+
+        for index in 0..this.futures.len() {
+            if let Poll::Ready(output) = this.futures[index].as_mut().poll(cx) {
+                this.futures.remove(index);
+                return Poll::Ready(Some(output));
+            }
+        }
+
+        Poll::Pending
+    }
+}